@@ -0,0 +1,35 @@
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde_json::json;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ApiError {
+    #[error("invalid vote account pubkey: {0}")]
+    InvalidPubkey(String),
+
+    #[error("no validator history account found for vote account {0}")]
+    AccountNotFound(String),
+
+    #[error("validator history account for {0} has no entry for epoch {1}")]
+    EpochNotFound(String, u16),
+
+    #[error("failed to deserialize validator history account data")]
+    InvalidAccountData,
+
+    #[error("rpc request failed: {0}")]
+    Rpc(#[from] solana_client::client_error::ClientError),
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let status = match &self {
+            ApiError::InvalidPubkey(_) => StatusCode::BAD_REQUEST,
+            ApiError::AccountNotFound(_) | ApiError::EpochNotFound(_, _) => StatusCode::NOT_FOUND,
+            ApiError::InvalidAccountData | ApiError::Rpc(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+        (status, Json(json!({ "error": self.to_string() }))).into_response()
+    }
+}
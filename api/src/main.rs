@@ -1,15 +1,44 @@
-use axum::{self, http::StatusCode, response::IntoResponse, routing::get, Json, Router};
-use std::net::SocketAddr;
+mod error;
+mod models;
+mod routes;
+
+use std::{net::SocketAddr, str::FromStr, sync::Arc};
+
+use axum::{routing::get, Router};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::pubkey::Pubkey;
+
+use routes::AppState;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let app = Router::new().route("/", get(|| async { "Hello, World!" }));
+    let rpc_url =
+        std::env::var("RPC_URL").unwrap_or_else(|_| "https://api.mainnet-beta.solana.com".into());
+    let program_id = std::env::var("VALIDATOR_HISTORY_PROGRAM_ID")
+        .ok()
+        .and_then(|id| Pubkey::from_str(&id).ok())
+        .unwrap_or(validator_history::ID);
+
+    let state = AppState {
+        rpc_client: Arc::new(RpcClient::new(rpc_url)),
+        program_id,
+    };
+
+    let app = Router::new()
+        .route(
+            "/validator/:vote_account/history",
+            get(routes::get_validator_history),
+        )
+        .route(
+            "/validator/:vote_account/epoch/:epoch",
+            get(routes::get_validator_epoch),
+        )
+        .route("/validators/superminority", get(routes::get_superminority))
+        .route("/validators/ranked", get(routes::get_ranked))
+        .with_state(state);
 
-    // run our app with hyper, listening globally on port 3000
     let addr = SocketAddr::from(([127, 0, 0, 1], 3000));
-    axum::Server::bind(&addr)
-        .serve(app.into_make_service())
-        .await
-        .unwrap();
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    axum::serve(listener, app).await?;
     Ok(())
 }
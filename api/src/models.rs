@@ -0,0 +1,132 @@
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use serde::{Deserialize, Serialize};
+use validator_history::state::{ClientType, ValidatorHistoryEntry};
+
+// Sentinel fields (`u64::MAX`, `u8::MAX`, etc.) are surfaced as JSON `null` so clients don't need
+// to know the on-chain "uninitialized" conventions.
+#[derive(Serialize)]
+pub struct ValidatorHistoryEntryResponse {
+    pub epoch: Option<u16>,
+    pub activated_stake_lamports: Option<u64>,
+    pub mev_commission: Option<u16>,
+    pub epoch_credits: Option<u32>,
+    pub commission: Option<u8>,
+    pub client_type: Option<ClientTypeResponse>,
+    pub version: Option<ClientVersionResponse>,
+    pub ip: Option<String>,
+    pub ip_v6: Option<String>,
+    pub is_superminority: Option<bool>,
+    pub is_delinquent: Option<bool>,
+    pub rank: Option<u32>,
+    pub vote_account_last_update_slot: Option<u64>,
+}
+
+#[derive(Serialize)]
+pub struct ClientVersionResponse {
+    pub major: u8,
+    pub minor: u8,
+    pub patch: u16,
+}
+
+// Mirrors `validator_history::state::ClientType`. Kept API-local, same as `ClientVersionResponse`
+// mirrors `ClientVersion`, rather than deriving serde on the on-chain enum.
+#[derive(Serialize)]
+pub enum ClientTypeResponse {
+    SolanaLabs,
+    JitoLabs,
+    Firedancer,
+    Agave,
+    Unknown,
+}
+
+impl From<ClientType> for ClientTypeResponse {
+    fn from(client_type: ClientType) -> Self {
+        match client_type {
+            ClientType::SolanaLabs => ClientTypeResponse::SolanaLabs,
+            ClientType::JitoLabs => ClientTypeResponse::JitoLabs,
+            ClientType::Firedancer => ClientTypeResponse::Firedancer,
+            ClientType::Agave => ClientTypeResponse::Agave,
+            ClientType::Unknown => ClientTypeResponse::Unknown,
+        }
+    }
+}
+
+// Branches on `ip_version` rather than inferring the sentinel from the `ip` bytes, since an
+// IPv6-only entry stores `[0; 4]` there, which isn't the `[u8::MAX; 4]` "unset" sentinel.
+fn ip_response(entry: &ValidatorHistoryEntry) -> (Option<String>, Option<String>) {
+    match entry.ip_version {
+        0 => (Some(Ipv4Addr::from(entry.ip).to_string()), None),
+        1 => (None, Some(Ipv6Addr::from(entry.ip_v6).to_string())),
+        _ => (
+            (entry.ip != [u8::MAX; 4]).then_some(Ipv4Addr::from(entry.ip).to_string()),
+            None,
+        ),
+    }
+}
+
+impl From<&ValidatorHistoryEntry> for ValidatorHistoryEntryResponse {
+    fn from(entry: &ValidatorHistoryEntry) -> Self {
+        let (ip, ip_v6) = ip_response(entry);
+        Self {
+            epoch: (entry.epoch != u16::MAX).then_some(entry.epoch),
+            activated_stake_lamports: (entry.activated_stake_lamports != u64::MAX)
+                .then_some(entry.activated_stake_lamports),
+            mev_commission: (entry.mev_commission != u16::MAX).then_some(entry.mev_commission),
+            epoch_credits: (entry.epoch_credits != u32::MAX).then_some(entry.epoch_credits),
+            commission: (entry.commission != u8::MAX).then_some(entry.commission),
+            client_type: (entry.client_type != u8::MAX)
+                .then_some(ClientType::from(entry.client_type).into()),
+            version: (entry.version.major != u8::MAX).then_some(ClientVersionResponse {
+                major: entry.version.major,
+                minor: entry.version.minor,
+                patch: entry.version.patch,
+            }),
+            ip,
+            ip_v6,
+            is_superminority: (entry.is_superminority != u8::MAX)
+                .then_some(entry.is_superminority == 1),
+            is_delinquent: (entry.is_delinquent != u8::MAX).then_some(entry.is_delinquent == 1),
+            rank: (entry.rank != u32::MAX).then_some(entry.rank),
+            vote_account_last_update_slot: (entry.vote_account_last_update_slot != u64::MAX)
+                .then_some(entry.vote_account_last_update_slot),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct ValidatorSummaryResponse {
+    pub vote_account: String,
+    pub latest: ValidatorHistoryEntryResponse,
+}
+
+#[derive(Deserialize)]
+pub struct PaginationParams {
+    #[serde(default = "default_page")]
+    pub page: usize,
+    #[serde(default = "default_page_size")]
+    pub limit: usize,
+}
+
+fn default_page() -> usize {
+    1
+}
+
+fn default_page_size() -> usize {
+    50
+}
+
+impl PaginationParams {
+    pub fn apply<T>(&self, items: Vec<T>) -> Vec<T> {
+        let start = self.page.saturating_sub(1) * self.limit;
+        items.into_iter().skip(start).take(self.limit).collect()
+    }
+}
+
+#[derive(Serialize)]
+pub struct PaginatedResponse<T> {
+    pub page: usize,
+    pub limit: usize,
+    pub total: usize,
+    pub items: Vec<T>,
+}
@@ -0,0 +1,188 @@
+use std::{str::FromStr, sync::Arc};
+
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use solana_client::{
+    nonblocking::rpc_client::RpcClient,
+    rpc_filter::{Memcmp, RpcFilterType},
+};
+use solana_sdk::{account::Account, commitment_config::CommitmentConfig, pubkey::Pubkey};
+use validator_history::state::ValidatorHistory;
+
+use crate::{
+    error::ApiError,
+    models::{
+        PaginatedResponse, PaginationParams, ValidatorHistoryEntryResponse,
+        ValidatorSummaryResponse,
+    },
+};
+
+#[derive(Clone)]
+pub struct AppState {
+    pub rpc_client: Arc<RpcClient>,
+    pub program_id: Pubkey,
+}
+
+fn parse_vote_account(vote_account: &str) -> Result<Pubkey, ApiError> {
+    Pubkey::from_str(vote_account).map_err(|_| ApiError::InvalidPubkey(vote_account.to_string()))
+}
+
+fn validator_history_address(program_id: &Pubkey, vote_account: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[ValidatorHistory::SEED, vote_account.as_ref()],
+        program_id,
+    )
+    .0
+}
+
+// Distinguishes a genuinely missing account (`AccountNotFound`) from a backend RPC failure
+// (`ApiError::Rpc`, via `?`) so a timeout or rate limit isn't reported as "no history".
+async fn fetch_validator_history_account(
+    state: &AppState,
+    vote_account: &Pubkey,
+) -> Result<Account, ApiError> {
+    let address = validator_history_address(&state.program_id, vote_account);
+    state
+        .rpc_client
+        .get_account_with_commitment(&address, CommitmentConfig::confirmed())
+        .await?
+        .value
+        .ok_or_else(|| ApiError::AccountNotFound(vote_account.to_string()))
+}
+
+fn deserialize_validator_history(account: &Account) -> Result<&ValidatorHistory, ApiError> {
+    // Anchor zero-copy accounts are prefixed with an 8-byte discriminator.
+    let data = account
+        .data
+        .get(8..)
+        .ok_or(ApiError::InvalidAccountData)?;
+    bytemuck::try_from_bytes(data).map_err(|_| ApiError::InvalidAccountData)
+}
+
+// Oldest-to-newest view of the CircBuf, skipping never-written slots.
+fn ordered_entries(history: &ValidatorHistory) -> Vec<&validator_history::state::ValidatorHistoryEntry> {
+    let buf = &history.history;
+    let len = buf.arr.len();
+    (0..len)
+        .map(|i| &buf.arr[(buf.idx as usize + 1 + i) % len])
+        .filter(|entry| entry.epoch != u16::MAX)
+        .collect()
+}
+
+pub async fn get_validator_history(
+    State(state): State<AppState>,
+    Path(vote_account): Path<String>,
+) -> Result<Json<Vec<ValidatorHistoryEntryResponse>>, ApiError> {
+    let vote_account = parse_vote_account(&vote_account)?;
+    let account = fetch_validator_history_account(&state, &vote_account).await?;
+    let history = deserialize_validator_history(&account)?;
+
+    Ok(Json(
+        ordered_entries(history)
+            .into_iter()
+            .map(ValidatorHistoryEntryResponse::from)
+            .collect(),
+    ))
+}
+
+pub async fn get_validator_epoch(
+    State(state): State<AppState>,
+    Path((vote_account, epoch)): Path<(String, u16)>,
+) -> Result<Json<ValidatorHistoryEntryResponse>, ApiError> {
+    let vote_account = parse_vote_account(&vote_account)?;
+    let account = fetch_validator_history_account(&state, &vote_account).await?;
+    let history = deserialize_validator_history(&account)?;
+
+    let entry = ordered_entries(history)
+        .into_iter()
+        .find(|entry| entry.epoch == epoch)
+        .ok_or_else(|| ApiError::EpochNotFound(vote_account.to_string(), epoch))?;
+
+    Ok(Json(ValidatorHistoryEntryResponse::from(entry)))
+}
+
+async fn fetch_all_validator_histories(
+    state: &AppState,
+) -> Result<Vec<(Pubkey, Vec<u8>)>, ApiError> {
+    let accounts = state
+        .rpc_client
+        .get_program_accounts_with_config(
+            &state.program_id,
+            solana_client::rpc_config::RpcProgramAccountsConfig {
+                filters: Some(vec![RpcFilterType::DataSize(ValidatorHistory::SIZE as u64)]),
+                account_config: solana_client::rpc_config::RpcAccountInfoConfig {
+                    encoding: Some(solana_account_decoder::UiAccountEncoding::Base64),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        )
+        .await?;
+    Ok(accounts
+        .into_iter()
+        .map(|(pubkey, account)| (pubkey, account.data))
+        .collect())
+}
+
+pub async fn get_superminority(
+    State(state): State<AppState>,
+    Query(pagination): Query<PaginationParams>,
+) -> Result<Json<PaginatedResponse<ValidatorSummaryResponse>>, ApiError> {
+    let accounts = fetch_all_validator_histories(&state).await?;
+
+    let mut summaries: Vec<ValidatorSummaryResponse> = accounts
+        .iter()
+        .filter_map(|(pubkey, data)| {
+            let data = data.get(8..)?;
+            let history: &ValidatorHistory = bytemuck::try_from_bytes(data).ok()?;
+            let latest = ordered_entries(history).into_iter().last()?;
+            if latest.is_superminority != 1 {
+                return None;
+            }
+            Some(ValidatorSummaryResponse {
+                vote_account: pubkey.to_string(),
+                latest: latest.into(),
+            })
+        })
+        .collect();
+
+    summaries.sort_by_key(|s| s.vote_account.clone());
+    let total = summaries.len();
+    Ok(Json(PaginatedResponse {
+        page: pagination.page,
+        limit: pagination.limit,
+        total,
+        items: pagination.apply(summaries),
+    }))
+}
+
+pub async fn get_ranked(
+    State(state): State<AppState>,
+    Query(pagination): Query<PaginationParams>,
+) -> Result<Json<PaginatedResponse<ValidatorSummaryResponse>>, ApiError> {
+    let accounts = fetch_all_validator_histories(&state).await?;
+
+    let mut summaries: Vec<ValidatorSummaryResponse> = accounts
+        .iter()
+        .filter_map(|(pubkey, data)| {
+            let data = data.get(8..)?;
+            let history: &ValidatorHistory = bytemuck::try_from_bytes(data).ok()?;
+            let latest = ordered_entries(history).into_iter().last()?;
+            Some(ValidatorSummaryResponse {
+                vote_account: pubkey.to_string(),
+                latest: latest.into(),
+            })
+        })
+        .collect();
+
+    summaries.sort_by_key(|s| s.latest.rank.unwrap_or(u32::MAX));
+    let total = summaries.len();
+    Ok(Json(PaginatedResponse {
+        page: pagination.page,
+        limit: pagination.limit,
+        total,
+        items: pagination.apply(summaries),
+    }))
+}